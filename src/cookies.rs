@@ -0,0 +1,119 @@
+use std::error::Error;
+use std::fs;
+
+use playwright::api::BrowserContext;
+use serde::{Deserialize, Serialize};
+
+/// A serializable Playwright cookie, enough to persist and restore a
+/// logged-in session between runs without re-running `Login`. Keeps the
+/// full attribute set so `Secure`/`HttpOnly` session cookies round-trip
+/// correctly instead of silently dropping them on reload.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Cookie {
+    pub name: String,
+    pub value: String,
+    pub domain: String,
+    pub path: String,
+    pub expires: f64,
+    pub http_only: bool,
+    pub secure: bool,
+    pub same_site: Option<SameSite>,
+}
+
+/// Mirrors Playwright's cookie `sameSite` attribute.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum SameSite {
+    Strict,
+    Lax,
+    None,
+}
+
+impl From<SameSite> for playwright::api::context::SameSite {
+    fn from(same_site: SameSite) -> Self {
+        match same_site {
+            SameSite::Strict => playwright::api::context::SameSite::Strict,
+            SameSite::Lax => playwright::api::context::SameSite::Lax,
+            SameSite::None => playwright::api::context::SameSite::None,
+        }
+    }
+}
+
+impl From<playwright::api::context::SameSite> for SameSite {
+    fn from(same_site: playwright::api::context::SameSite) -> Self {
+        match same_site {
+            playwright::api::context::SameSite::Strict => SameSite::Strict,
+            playwright::api::context::SameSite::Lax => SameSite::Lax,
+            playwright::api::context::SameSite::None => SameSite::None,
+        }
+    }
+}
+
+/// Writes every cookie in `context` to `path` as JSON.
+pub async fn save_cookies(context: &BrowserContext, path: &str) -> Result<(), Box<dyn Error>> {
+    let cookies = context.cookies(None).await?;
+    let cookies: Vec<Cookie> = cookies
+        .into_iter()
+        .map(|c| Cookie {
+            name: c.name,
+            value: c.value,
+            domain: c.domain,
+            path: c.path,
+            expires: c.expires,
+            http_only: c.http_only,
+            secure: c.secure,
+            same_site: c.same_site.map(SameSite::from),
+        })
+        .collect();
+    fs::write(path, serde_json::to_string_pretty(&cookies)?)?;
+    Ok(())
+}
+
+/// Reads cookies previously written by [`save_cookies`] and adds them to `context`.
+pub async fn load_cookies(context: &BrowserContext, path: &str) -> Result<(), Box<dyn Error>> {
+    let contents = fs::read_to_string(path)?;
+    let cookies: Vec<Cookie> = serde_json::from_str(&contents)?;
+    add_cookies(context, cookies).await
+}
+
+/// Adds a single cookie to `context`.
+pub async fn add_cookie(
+    context: &BrowserContext,
+    name: &str,
+    value: &str,
+    domain: &str,
+    path: &str,
+) -> Result<(), Box<dyn Error>> {
+    add_cookies(
+        context,
+        vec![Cookie {
+            name: name.to_string(),
+            value: value.to_string(),
+            domain: domain.to_string(),
+            path: path.to_string(),
+            expires: -1.0,
+            http_only: false,
+            secure: false,
+            same_site: None,
+        }],
+    )
+    .await
+}
+
+async fn add_cookies(context: &BrowserContext, cookies: Vec<Cookie>) -> Result<(), Box<dyn Error>> {
+    let cookies: Vec<playwright::api::context::Cookie> = cookies
+        .into_iter()
+        .map(|c| playwright::api::context::Cookie {
+            name: c.name,
+            value: c.value,
+            domain: Some(c.domain),
+            path: Some(c.path),
+            expires: c.expires,
+            http_only: c.http_only,
+            secure: c.secure,
+            same_site: c.same_site.map(Into::into),
+            ..Default::default()
+        })
+        .collect();
+    context.add_cookies(&cookies).await?;
+    Ok(())
+}
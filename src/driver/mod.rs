@@ -0,0 +1,47 @@
+use std::error::Error;
+
+mod webdriver_driver;
+
+pub use webdriver_driver::WebDriverDriver;
+
+/// A handle to a single element matched by `Driver::query`/`query_all`.
+#[async_trait::async_trait]
+pub trait DriverElement: Send + Sync {
+    async fn get_attribute(&self, name: &str) -> Result<Option<String>, Box<dyn Error>>;
+    async fn text_content(&self) -> Result<Option<String>, Box<dyn Error>>;
+    async fn is_checked(&self) -> Result<bool, Box<dyn Error>>;
+    async fn click(&self) -> Result<(), Box<dyn Error>>;
+}
+
+/// The primitive browser operations `perform_driver_action` needs, abstracted
+/// over the automation backend; `perform_action` itself takes a Playwright
+/// `Page` and does not go through this trait. Implement this to run scraping tasks against a
+/// backend other than Playwright, e.g. an existing geckodriver/chromedriver
+/// setup via WebDriver.
+///
+/// There's deliberately no `PlaywrightDriver`: `perform_scraping` already
+/// runs the full `Action` set, including frames, cookies, and registry
+/// extraction, directly against a Playwright `Page`, so reducing it to this
+/// trait's narrower surface would be a downgrade rather than a shared path.
+/// `Driver` exists for backends, like WebDriver, that have no such
+/// full-featured pipeline of their own.
+#[async_trait::async_trait]
+pub trait Driver: Send + Sync {
+    async fn goto(&self, url: &str) -> Result<(), Box<dyn Error>>;
+    async fn click(&self, selector: &str) -> Result<(), Box<dyn Error>>;
+    async fn fill(&self, selector: &str, text: &str) -> Result<(), Box<dyn Error>>;
+    async fn query(&self, selector: &str) -> Result<Option<Box<dyn DriverElement>>, Box<dyn Error>>;
+    async fn query_all(&self, selector: &str) -> Result<Vec<Box<dyn DriverElement>>, Box<dyn Error>>;
+    async fn eval(&self, script: &str) -> Result<(), Box<dyn Error>>;
+    async fn hover(&self, selector: &str) -> Result<(), Box<dyn Error>>;
+    async fn dblclick(&self, selector: &str) -> Result<(), Box<dyn Error>>;
+    async fn right_click(&self, selector: &str) -> Result<(), Box<dyn Error>>;
+}
+
+/// Which automation backend a `ScrapingTask` should run against.
+#[derive(Debug, Clone, Copy, Default, serde::Deserialize, serde::Serialize)]
+pub enum Backend {
+    #[default]
+    Playwright,
+    WebDriver,
+}
@@ -0,0 +1,123 @@
+use std::error::Error;
+
+use thirtyfour::prelude::*;
+
+use super::{Driver, DriverElement};
+
+/// `Driver` implementation backed by a WebDriver session (geckodriver,
+/// chromedriver, ...) via `thirtyfour`, for users who already run that
+/// infrastructure instead of Playwright's bundled browsers.
+pub struct WebDriverDriver {
+    client: WebDriver,
+}
+
+impl WebDriverDriver {
+    pub fn new(client: WebDriver) -> Self {
+        Self { client }
+    }
+
+    /// Connects to a running WebDriver server (e.g. a local geckodriver or
+    /// chromedriver) and wraps the resulting session as a `Driver`.
+    pub async fn connect(
+        webdriver_url: &str,
+        capabilities: impl Into<Capabilities>,
+    ) -> Result<Self, Box<dyn Error>> {
+        let client = WebDriver::new(webdriver_url, capabilities).await?;
+        Ok(Self::new(client))
+    }
+}
+
+#[async_trait::async_trait]
+impl DriverElement for WebElement {
+    async fn get_attribute(&self, name: &str) -> Result<Option<String>, Box<dyn Error>> {
+        Ok(self.attr(name).await?)
+    }
+
+    async fn text_content(&self) -> Result<Option<String>, Box<dyn Error>> {
+        Ok(Some(self.text().await?))
+    }
+
+    async fn is_checked(&self) -> Result<bool, Box<dyn Error>> {
+        Ok(self.is_selected().await?)
+    }
+
+    async fn click(&self) -> Result<(), Box<dyn Error>> {
+        WebElement::click(self).await?;
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl Driver for WebDriverDriver {
+    async fn goto(&self, url: &str) -> Result<(), Box<dyn Error>> {
+        self.client.goto(url).await?;
+        Ok(())
+    }
+
+    async fn click(&self, selector: &str) -> Result<(), Box<dyn Error>> {
+        self.client.find(By::Css(selector)).await?.click().await?;
+        Ok(())
+    }
+
+    async fn fill(&self, selector: &str, text: &str) -> Result<(), Box<dyn Error>> {
+        self.client
+            .find(By::Css(selector))
+            .await?
+            .send_keys(text)
+            .await?;
+        Ok(())
+    }
+
+    async fn query(&self, selector: &str) -> Result<Option<Box<dyn DriverElement>>, Box<dyn Error>> {
+        match self.client.find(By::Css(selector)).await {
+            Ok(element) => Ok(Some(Box::new(element) as Box<dyn DriverElement>)),
+            Err(WebDriverError::NoSuchElement(_)) => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    async fn query_all(&self, selector: &str) -> Result<Vec<Box<dyn DriverElement>>, Box<dyn Error>> {
+        let elements = self.client.find_all(By::Css(selector)).await?;
+        Ok(elements
+            .into_iter()
+            .map(|e| Box::new(e) as Box<dyn DriverElement>)
+            .collect())
+    }
+
+    async fn eval(&self, script: &str) -> Result<(), Box<dyn Error>> {
+        self.client.execute(script, vec![]).await?;
+        Ok(())
+    }
+
+    async fn hover(&self, selector: &str) -> Result<(), Box<dyn Error>> {
+        let element = self.client.find(By::Css(selector)).await?;
+        self.client
+            .action_chain()
+            .move_to_element_center(&element)
+            .perform()
+            .await?;
+        Ok(())
+    }
+
+    async fn dblclick(&self, selector: &str) -> Result<(), Box<dyn Error>> {
+        let element = self.client.find(By::Css(selector)).await?;
+        self.client
+            .action_chain()
+            .move_to_element_center(&element)
+            .double_click()
+            .perform()
+            .await?;
+        Ok(())
+    }
+
+    async fn right_click(&self, selector: &str) -> Result<(), Box<dyn Error>> {
+        let element = self.client.find(By::Css(selector)).await?;
+        self.client
+            .action_chain()
+            .move_to_element_center(&element)
+            .context_click()
+            .perform()
+            .await?;
+        Ok(())
+    }
+}
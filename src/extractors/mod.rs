@@ -0,0 +1,25 @@
+use std::error::Error;
+
+use playwright::api::Page;
+use url::Url;
+
+mod registry;
+
+pub use registry::ExtractorRegistry;
+
+/// A site-specific extraction strategy.
+///
+/// Implementors encapsulate the selectors and post-processing needed to
+/// turn a loaded page into a JSON record for one site, so a
+/// `ScrapingTask` can rely on a registered extractor instead of spelling
+/// out every selector by hand. Add a new site by implementing this trait
+/// (e.g. `TwitterExtractor`, `AmazonExtractor`) and registering it with
+/// an `ExtractorRegistry`.
+#[async_trait::async_trait]
+pub trait Extractor: Send + Sync {
+    /// Returns true if this extractor knows how to handle `url`.
+    fn matches(&self, url: &Url) -> bool;
+
+    /// Extracts a JSON record from the current page.
+    async fn extract(&self, page: &Page) -> Result<serde_json::Value, Box<dyn Error>>;
+}
@@ -0,0 +1,106 @@
+use std::error::Error;
+
+use playwright::api::Page;
+use url::Url;
+
+use super::Extractor;
+
+/// Holds the registered [`Extractor`]s and dispatches to the first one
+/// whose `matches` accepts a given URL.
+#[derive(Default)]
+pub struct ExtractorRegistry {
+    extractors: Vec<Box<dyn Extractor>>,
+}
+
+impl ExtractorRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers an extractor. Extractors are tried in registration order,
+    /// so put more specific matchers before general-purpose fallbacks.
+    pub fn register(&mut self, extractor: Box<dyn Extractor>) -> &mut Self {
+        self.extractors.push(extractor);
+        self
+    }
+
+    /// Returns the first registered extractor that matches `url`, if any.
+    pub fn find(&self, url: &Url) -> Option<&dyn Extractor> {
+        self.extractors
+            .iter()
+            .find(|extractor| extractor.matches(url))
+            .map(|extractor| extractor.as_ref())
+    }
+
+    /// Runs the extractor matching `url` against `page`, if one is registered.
+    pub async fn extract(
+        &self,
+        url: &Url,
+        page: &Page,
+    ) -> Result<Option<serde_json::Value>, Box<dyn Error>> {
+        match self.find(url) {
+            Some(extractor) => Ok(Some(extractor.extract(page).await?)),
+            None => Ok(None),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct DomainExtractor {
+        domains: &'static [&'static str],
+    }
+
+    #[async_trait::async_trait]
+    impl Extractor for DomainExtractor {
+        fn matches(&self, url: &Url) -> bool {
+            url.domain().is_some_and(|domain| self.domains.contains(&domain))
+        }
+
+        async fn extract(&self, _page: &Page) -> Result<serde_json::Value, Box<dyn Error>> {
+            Ok(serde_json::json!({ "domains": self.domains }))
+        }
+    }
+
+    #[test]
+    fn find_returns_none_when_nothing_registered() {
+        let registry = ExtractorRegistry::new();
+        let url = Url::parse("https://example.com").unwrap();
+        assert!(registry.find(&url).is_none());
+    }
+
+    #[test]
+    fn find_dispatches_to_matching_extractor() {
+        let mut registry = ExtractorRegistry::new();
+        registry.register(Box::new(DomainExtractor {
+            domains: &["a.com"],
+        }));
+        registry.register(Box::new(DomainExtractor {
+            domains: &["b.com"],
+        }));
+
+        let url = Url::parse("https://b.com/page").unwrap();
+        assert!(registry.find(&url).is_some());
+
+        let url = Url::parse("https://c.com/page").unwrap();
+        assert!(registry.find(&url).is_none());
+    }
+
+    #[test]
+    fn find_prefers_earlier_registration_order() {
+        let mut registry = ExtractorRegistry::new();
+        // Both match a.com; only the first also matches b.com, so checking
+        // which one `find` returns for a.com tells us which was picked.
+        registry.register(Box::new(DomainExtractor {
+            domains: &["a.com", "b.com"],
+        }));
+        registry.register(Box::new(DomainExtractor {
+            domains: &["a.com"],
+        }));
+
+        let found = registry.find(&Url::parse("https://a.com").unwrap()).unwrap();
+        assert!(found.matches(&Url::parse("https://b.com").unwrap()));
+    }
+}
@@ -1,6 +1,16 @@
 use playwright::{api::MouseButton, Playwright};
 use serde::{Deserialize, Serialize};
 use std::{error::Error, fs};
+use url::Url;
+
+pub mod cookies;
+pub mod driver;
+pub mod extractors;
+pub mod report;
+
+use driver::{Backend, Driver};
+use extractors::ExtractorRegistry;
+use report::{ActionEvent, ActionOutcome, TaskReport};
 
 #[derive(Debug, Deserialize, Serialize)]
 pub enum Action {
@@ -18,6 +28,9 @@ pub enum Action {
     Extract {
         selector: String,
         attribute: Option<String>,
+        key: String,
+        #[serde(default)]
+        multiple: bool,
     },
     Wait {
         milliseconds: u64,
@@ -30,6 +43,7 @@ pub enum Action {
         username: String,
         password: String,
         submit_selector: String,
+        success_selector: String,
     },
     Navigate {
         selector: String,
@@ -59,49 +73,157 @@ pub enum Action {
         url: String,
         dist_path: String,
     },
+    SaveCookies {
+        path: String,
+    },
+    LoadCookies {
+        path: String,
+    },
+    AddCookie {
+        name: String,
+        value: String,
+        domain: String,
+        path: String,
+    },
+    WaitForSelector {
+        selector: String,
+        state: WaitForState,
+        timeout_ms: u64,
+    },
+    WaitForNavigation {
+        timeout_ms: u64,
+    },
+    Screenshot {
+        path: String,
+        full_page: bool,
+    },
+    WithinFrame {
+        frame_selector: String,
+        actions: Vec<Action>,
+    },
+}
+
+/// The element state `Action::WaitForSelector` waits for, mirroring
+/// Playwright's `waitForSelector` `state` option.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub enum WaitForState {
+    Attached,
+    Visible,
+    Hidden,
+    Detached,
+}
+
+impl From<WaitForState> for playwright::api::FrameState {
+    fn from(state: WaitForState) -> Self {
+        match state {
+            WaitForState::Attached => playwright::api::FrameState::Attached,
+            WaitForState::Visible => playwright::api::FrameState::Visible,
+            WaitForState::Hidden => playwright::api::FrameState::Hidden,
+            WaitForState::Detached => playwright::api::FrameState::Detached,
+        }
+    }
 }
 
 #[derive(Debug, Deserialize, Serialize)]
 pub struct ScrapingTask {
     pub name: String,
     pub actions: Vec<Action>,
+    #[serde(default)]
+    pub backend: Backend,
+    /// WebDriver server URL (e.g. a local geckodriver/chromedriver) to
+    /// connect to when `backend` is `Backend::WebDriver`. Unused otherwise.
+    #[serde(default)]
+    pub webdriver_url: Option<String>,
+}
+
+/// Shapes the text/attribute matches collected for `Action::Extract` into the
+/// JSON value that gets stored under its `key`: a single string for
+/// `multiple: false`, or an array when `multiple: true`. Returns `None` when
+/// nothing matched and `multiple` is `false`, so the caller can skip emitting
+/// the key entirely.
+fn shape_extract(matches: Vec<String>, multiple: bool) -> Option<serde_json::Value> {
+    if multiple {
+        Some(serde_json::Value::Array(
+            matches.into_iter().map(serde_json::Value::String).collect(),
+        ))
+    } else {
+        matches.into_iter().next().map(serde_json::Value::String)
+    }
+}
+
+/// The variant name of an `Action`, used to label report events.
+fn action_name(action: &Action) -> &'static str {
+    match action {
+        Action::GoTo { .. } => "GoTo",
+        Action::Click { .. } => "Click",
+        Action::Input { .. } => "Input",
+        Action::Extract { .. } => "Extract",
+        Action::Wait { .. } => "Wait",
+        Action::Login { .. } => "Login",
+        Action::Navigate { .. } => "Navigate",
+        Action::FillCheckbox { .. } => "FillCheckbox",
+        Action::SelectDropdown { .. } => "SelectDropdown",
+        Action::Hover { .. } => "Hover",
+        Action::DoubleClick { .. } => "DoubleClick",
+        Action::RightClick { .. } => "RightClick",
+        Action::RunScript { .. } => "RunScript",
+        Action::DownloadFile { .. } => "DownloadFile",
+        Action::SaveCookies { .. } => "SaveCookies",
+        Action::LoadCookies { .. } => "LoadCookies",
+        Action::AddCookie { .. } => "AddCookie",
+        Action::WaitForSelector { .. } => "WaitForSelector",
+        Action::WaitForNavigation { .. } => "WaitForNavigation",
+        Action::Screenshot { .. } => "Screenshot",
+        Action::WithinFrame { .. } => "WithinFrame",
+    }
 }
 
 pub async fn perform_action(
     page: &playwright::api::Page,
     action: &Action,
-) -> Result<(), Box<dyn Error>> {
+) -> Result<Vec<(String, serde_json::Value)>, Box<dyn Error>> {
     // 各アクションに対応する処理を実行します
     match action {
-        Action::GoTo { url } => page.goto_builder(url).goto().await?,
+        Action::GoTo { url } => {
+            page.goto_builder(url).goto().await?;
+            return Ok(Vec::new());
+        }
         Action::Click { selector } => {
             page.click_builder(selector).click().await?;
-            return Ok(());
+            return Ok(Vec::new());
         }
         Action::Input { selector, text } => {
             page.fill_builder(selector, text).fill().await?;
-            return Ok(());
+            return Ok(Vec::new());
         }
         Action::Extract {
             selector,
             attribute,
+            key,
+            multiple,
         } => {
             let elements = page.query_selector_all(selector).await?;
+            let mut matches = Vec::new();
             for element in elements {
                 let result = match attribute {
                     Some(attr) => element.get_attribute(attr).await?,
                     None => element.text_content().await?,
                 };
                 if let Some(content) = result {
-                    println!("this content: {}", content); // ここでは単純に出力していますが、用途に応じて処理を変更可能
-                    return Ok(());
+                    matches.push(content);
+                    if !*multiple {
+                        break;
+                    }
                 }
             }
-            return Ok(());
+            return Ok(match shape_extract(matches, *multiple) {
+                Some(value) => vec![(key.clone(), value)],
+                None => Vec::new(),
+            });
         }
         Action::Wait { milliseconds } => {
             page.wait_for_timeout(*milliseconds as f64).await;
-            return Ok(());
+            return Ok(Vec::new());
         }
         Action::Login {
             url,
@@ -110,6 +232,7 @@ pub async fn perform_action(
             username,
             password,
             submit_selector,
+            success_selector,
         } => {
             page.goto_builder(url).goto().await?;
             page.fill_builder(username_selector, username)
@@ -119,8 +242,10 @@ pub async fn perform_action(
                 .fill()
                 .await?;
             page.click_builder(submit_selector).click().await?;
-            page.wait_for_timeout(2000f64).await; // wait for login to complete
-            return Ok(());
+            page.wait_for_selector_builder(success_selector)
+                .wait_for_selector()
+                .await?;
+            return Ok(Vec::new());
         }
         Action::Navigate {
             selector,
@@ -128,11 +253,11 @@ pub async fn perform_action(
         } => {
             let element = match page.query_selector(selector).await? {
                 Some(element) => element,
-                None => return Ok(()),
+                None => return Ok(Vec::new()),
             };
             let href = match element.get_attribute(&attribute).await {
                 Ok(Some(href)) => href,
-                _ => return Ok(()),
+                _ => return Ok(Vec::new()),
             };
             match page.goto_builder(&href).goto().await {
                 Ok(_) => (),
@@ -142,7 +267,7 @@ pub async fn perform_action(
                 }
             }
 
-            return Ok(());
+            return Ok(Vec::new());
         }
         Action::FillCheckbox { selector, checked } => {
             let checkbox = match page.query_selector(selector).await {
@@ -155,62 +280,470 @@ pub async fn perform_action(
                 checkbox.click_builder().click().await?;
             }
 
-            return Ok(());
+            return Ok(Vec::new());
         }
         Action::SelectDropdown { selector, option } => {
             page.select_option_builder(selector)
                 .add_value(option.to_string());
 
-            return Ok(());
+            return Ok(Vec::new());
         }
         Action::Hover { selector } => {
             page.hover_builder(selector).clear_force().goto().await?;
-            return Ok(());
+            return Ok(Vec::new());
         }
         Action::DoubleClick { selector } => {
             page.dblclick_builder(selector).dblclick().await?;
-            return Ok(());
+            return Ok(Vec::new());
         }
         Action::RightClick { selector } => {
             page.click_builder(selector)
                 .button(MouseButton::Right)
                 .click()
                 .await?;
-            return Ok(());
+            return Ok(Vec::new());
         }
         Action::RunScript { script } => {
             page.eval(script.as_str()).await?;
-            return Ok(());
+            return Ok(Vec::new());
         }
         Action::DownloadFile { url, dist_path } => {
             let response = page.goto_builder(url).goto().await?;
             let body = response.unwrap().body().await?;
             std::fs::write(dist_path, body)?;
-            return Ok(());
+            return Ok(Vec::new());
+        }
+        Action::SaveCookies { path } => {
+            cookies::save_cookies(page.context(), path).await?;
+            return Ok(Vec::new());
+        }
+        Action::LoadCookies { path } => {
+            cookies::load_cookies(page.context(), path).await?;
+            return Ok(Vec::new());
+        }
+        Action::AddCookie {
+            name,
+            value,
+            domain,
+            path,
+        } => {
+            cookies::add_cookie(page.context(), name, value, domain, path).await?;
+            return Ok(Vec::new());
+        }
+        Action::WaitForSelector {
+            selector,
+            state,
+            timeout_ms,
+        } => {
+            page.wait_for_selector_builder(selector)
+                .state(state.clone().into())
+                .timeout(*timeout_ms as f64)
+                .wait_for_selector()
+                .await?;
+            return Ok(Vec::new());
+        }
+        Action::WaitForNavigation { timeout_ms } => {
+            page.wait_for_navigation_builder()
+                .timeout(*timeout_ms as f64)
+                .wait_for_navigation()
+                .await?;
+            return Ok(Vec::new());
+        }
+        Action::Screenshot { path, full_page } => {
+            page.screenshot_builder()
+                .full_page(*full_page)
+                .path(path.into())
+                .screenshot()
+                .await?;
+            return Ok(Vec::new());
+        }
+        Action::WithinFrame {
+            frame_selector,
+            actions,
+        } => {
+            let element = match page.query_selector(frame_selector).await? {
+                Some(element) => element,
+                None => return Err(format!("frame not found: {}", frame_selector).into()),
+            };
+            let frame = match element.content_frame().await? {
+                Some(frame) => frame,
+                None => return Err(format!("element is not a frame: {}", frame_selector).into()),
+            };
+            let mut extracted = Vec::new();
+            for nested in actions {
+                extracted.extend(perform_frame_action(&frame, nested).await?);
+            }
+            return Ok(extracted);
         }
     };
 
-    Ok(())
+    Ok(Vec::new())
 }
 
-pub async fn perform_scraping(task: &ScrapingTask) -> Result<Vec<String>, Box<dyn Error>> {
+/// Runs the subset of [`Action`]s that make sense inside an iframe against
+/// its content `Frame`, for `Action::WithinFrame`. Page-level actions such
+/// as `GoTo` or `Login` aren't meaningful here and are rejected. Extracted
+/// key/value pairs are returned so the caller can merge them into the task
+/// output instead of discarding them.
+fn perform_frame_action<'a>(
+    frame: &'a playwright::api::Frame,
+    action: &'a Action,
+) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<Vec<(String, serde_json::Value)>, Box<dyn Error>>> + 'a>>
+{
+    Box::pin(async move {
+        match action {
+            Action::Click { selector } => {
+                frame.click_builder(selector).click().await?;
+                Ok(Vec::new())
+            }
+            Action::Input { selector, text } => {
+                frame.fill_builder(selector, text).fill().await?;
+                Ok(Vec::new())
+            }
+            Action::Extract {
+                selector,
+                attribute,
+                key,
+                multiple,
+            } => {
+                let elements = frame.query_selector_all(selector).await?;
+                let mut matches = Vec::new();
+                for element in elements {
+                    let result = match attribute {
+                        Some(attr) => element.get_attribute(attr).await?,
+                        None => element.text_content().await?,
+                    };
+                    if let Some(content) = result {
+                        matches.push(content);
+                        if !*multiple {
+                            break;
+                        }
+                    }
+                }
+                Ok(match shape_extract(matches, *multiple) {
+                    Some(value) => vec![(key.clone(), value)],
+                    None => Vec::new(),
+                })
+            }
+            Action::Hover { selector } => {
+                frame.hover_builder(selector).hover().await?;
+                Ok(Vec::new())
+            }
+            Action::DoubleClick { selector } => {
+                frame.dblclick_builder(selector).dblclick().await?;
+                Ok(Vec::new())
+            }
+            Action::RightClick { selector } => {
+                frame
+                    .click_builder(selector)
+                    .button(MouseButton::Right)
+                    .click()
+                    .await?;
+                Ok(Vec::new())
+            }
+            Action::WaitForSelector {
+                selector,
+                state,
+                timeout_ms,
+            } => {
+                frame
+                    .wait_for_selector_builder(selector)
+                    .state(state.clone().into())
+                    .timeout(*timeout_ms as f64)
+                    .wait_for_selector()
+                    .await?;
+                Ok(Vec::new())
+            }
+            Action::WithinFrame {
+                frame_selector,
+                actions,
+            } => {
+                let element = match frame.query_selector(frame_selector).await? {
+                    Some(element) => element,
+                    None => return Err(format!("frame not found: {}", frame_selector).into()),
+                };
+                let nested_frame = match element.content_frame().await? {
+                    Some(nested_frame) => nested_frame,
+                    None => {
+                        return Err(format!("element is not a frame: {}", frame_selector).into())
+                    }
+                };
+                let mut extracted = Vec::new();
+                for nested in actions {
+                    extracted.extend(perform_frame_action(&nested_frame, nested).await?);
+                }
+                Ok(extracted)
+            }
+            other => Err(format!("action not supported within a frame: {:?}", other).into()),
+        }
+    })
+}
+
+fn merge_into(record: &mut serde_json::Map<String, serde_json::Value>, key: String, value: serde_json::Value) {
+    match (record.get_mut(&key), value) {
+        (Some(serde_json::Value::Array(existing)), serde_json::Value::Array(mut new)) => {
+            existing.append(&mut new);
+        }
+        (_, value) => {
+            record.insert(key, value);
+        }
+    }
+}
+
+pub async fn perform_scraping(
+    task: &ScrapingTask,
+    registry: &ExtractorRegistry,
+) -> Result<serde_json::Value, Box<dyn Error>> {
     let playwright = Playwright::initialize().await?;
     playwright.install_chromium()?;
     let browser = playwright.chromium().launcher().launch().await?;
     let context = browser.context_builder().build().await?;
     let page = context.new_page().await?;
 
-    let mut results = Vec::new();
+    let mut record = serde_json::Map::new();
 
     for action in &task.actions {
-        perform_action(&page, action).await?;
+        for (key, value) in perform_action(&page, action).await? {
+            merge_into(&mut record, key, value);
+        }
+
+        if matches!(action, Action::GoTo { .. } | Action::Login { .. }) {
+            let current_url = Url::parse(&page.url()?)?;
+            if let Some(serde_json::Value::Object(extracted)) =
+                registry.extract(&current_url, &page).await?
+            {
+                for (key, value) in extracted {
+                    merge_into(&mut record, key, value);
+                }
+            }
+        }
     }
 
     let run_before_unload = false;
     browser.close().await?;
     page.close(Some(run_before_unload)).await?;
 
-    Ok(results)
+    Ok(serde_json::Value::Object(record))
+}
+
+/// Like `perform_scraping`, but emits an `ActionEvent` per action on `events`
+/// and returns a `TaskReport` summarizing the run, so callers get
+/// machine-readable progress and per-action timing instead of `println!`s.
+pub async fn perform_scraping_reported(
+    task: &ScrapingTask,
+    registry: &ExtractorRegistry,
+    events: tokio::sync::mpsc::Sender<ActionEvent>,
+) -> Result<(serde_json::Value, TaskReport), Box<dyn Error>> {
+    let playwright = Playwright::initialize().await?;
+    playwright.install_chromium()?;
+    let browser = playwright.chromium().launcher().launch().await?;
+    let context = browser.context_builder().build().await?;
+    let page = context.new_page().await?;
+
+    let mut record = serde_json::Map::new();
+    let mut passed = 0;
+    let mut failed = 0;
+    let run_start = std::time::Instant::now();
+
+    for (index, action) in task.actions.iter().enumerate() {
+        let name = action_name(action).to_string();
+        let _ = events
+            .send(ActionEvent::Start {
+                index,
+                action_name: name.clone(),
+            })
+            .await;
+
+        let action_start = std::time::Instant::now();
+        let outcome = perform_action(&page, action).await;
+        let duration_ms = action_start.elapsed().as_millis();
+
+        let result = match &outcome {
+            Ok(_) => {
+                passed += 1;
+                ActionOutcome::Ok
+            }
+            Err(e) => {
+                failed += 1;
+                ActionOutcome::Failed(e.to_string())
+            }
+        };
+        let _ = events
+            .send(ActionEvent::Finished {
+                index,
+                action_name: name,
+                duration_ms,
+                result,
+            })
+            .await;
+
+        // Keep running the remaining actions even if one fails, like a test
+        // runner would, so the report reflects every action's outcome rather
+        // than aborting (and silently reporting zero failures) on the first.
+        let succeeded = outcome.is_ok();
+        if let Ok(extracted) = outcome {
+            for (key, value) in extracted {
+                merge_into(&mut record, key, value);
+            }
+        }
+
+        // Only consult the registry after an action that actually succeeded
+        // in navigating, and don't let a URL/registry error here abort the
+        // run either — the point of this function is to finish the report.
+        if succeeded && matches!(action, Action::GoTo { .. } | Action::Login { .. }) {
+            if let Ok(url_str) = page.url() {
+                if let Ok(current_url) = Url::parse(&url_str) {
+                    if let Ok(Some(serde_json::Value::Object(extracted))) =
+                        registry.extract(&current_url, &page).await
+                    {
+                        for (key, value) in extracted {
+                            merge_into(&mut record, key, value);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    let run_before_unload = false;
+    browser.close().await?;
+    page.close(Some(run_before_unload)).await?;
+
+    let report = TaskReport {
+        name: task.name.clone(),
+        total: task.actions.len(),
+        passed,
+        failed,
+        total_duration_ms: run_start.elapsed().as_millis(),
+    };
+
+    Ok((serde_json::Value::Object(record), report))
+}
+
+/// Runs one `Action` against a backend-agnostic [`Driver`] instead of a
+/// Playwright `Page` directly. Only covers the actions expressible in terms
+/// of `Driver`'s primitives (navigation, click/fill/hover/double/right-click,
+/// script eval and selector-based extraction); actions with no `Driver`
+/// equivalent (cookies, frames, screenshots, selector waits, ...) return an
+/// error, since `Driver` implementations like `WebDriverDriver` have no way
+/// to perform them.
+pub async fn perform_driver_action<D: Driver + ?Sized>(
+    driver: &D,
+    action: &Action,
+) -> Result<Option<(String, serde_json::Value)>, Box<dyn Error>> {
+    match action {
+        Action::GoTo { url } => {
+            driver.goto(url).await?;
+            Ok(None)
+        }
+        Action::Click { selector } => {
+            driver.click(selector).await?;
+            Ok(None)
+        }
+        Action::Input { selector, text } => {
+            driver.fill(selector, text).await?;
+            Ok(None)
+        }
+        Action::Extract {
+            selector,
+            attribute,
+            key,
+            multiple,
+        } => {
+            let elements = if *multiple {
+                driver.query_all(selector).await?
+            } else {
+                match driver.query(selector).await? {
+                    Some(element) => vec![element],
+                    None => Vec::new(),
+                }
+            };
+
+            let mut matches = Vec::new();
+            for element in elements {
+                let result = match attribute {
+                    Some(attr) => element.get_attribute(attr).await?,
+                    None => element.text_content().await?,
+                };
+                if let Some(content) = result {
+                    matches.push(content);
+                }
+            }
+
+            Ok(shape_extract(matches, *multiple).map(|value| (key.clone(), value)))
+        }
+        Action::FillCheckbox { selector, checked } => {
+            let checkbox = match driver.query(selector).await? {
+                Some(checkbox) => checkbox,
+                None => return Err("Failed to find checkbox".into()),
+            };
+            if checkbox.is_checked().await? != *checked {
+                checkbox.click().await?;
+            }
+            Ok(None)
+        }
+        Action::Hover { selector } => {
+            driver.hover(selector).await?;
+            Ok(None)
+        }
+        Action::DoubleClick { selector } => {
+            driver.dblclick(selector).await?;
+            Ok(None)
+        }
+        Action::RightClick { selector } => {
+            driver.right_click(selector).await?;
+            Ok(None)
+        }
+        Action::RunScript { script } => {
+            driver.eval(script).await?;
+            Ok(None)
+        }
+        other => Err(format!("action not supported on this backend: {:?}", other).into()),
+    }
+}
+
+/// Runs `task` against an already-constructed [`Driver`], merging each
+/// action's extraction into a single JSON record. Unlike `perform_scraping`,
+/// this doesn't own the browser/session lifecycle, and only supports the
+/// subset of `Action` variants `perform_driver_action` implements — construct
+/// a `WebDriverDriver` around an existing session and pass it in.
+pub async fn perform_scraping_with_driver<D: Driver + ?Sized>(
+    driver: &D,
+    task: &ScrapingTask,
+) -> Result<serde_json::Value, Box<dyn Error>> {
+    let mut record = serde_json::Map::new();
+
+    for action in &task.actions {
+        if let Some((key, value)) = perform_driver_action(driver, action).await? {
+            merge_into(&mut record, key, value);
+        }
+    }
+
+    Ok(serde_json::Value::Object(record))
+}
+
+/// Runs `task` against whichever backend `task.backend` selects, owning that
+/// backend's browser/session lifecycle. `Backend::WebDriver` connects to the
+/// WebDriver server at `task.webdriver_url` (e.g. a local
+/// geckodriver/chromedriver) using Chrome capabilities; that field is
+/// required for `Backend::WebDriver` and ignored for `Backend::Playwright`.
+pub async fn run_scraping_task(
+    task: &ScrapingTask,
+    registry: &ExtractorRegistry,
+) -> Result<serde_json::Value, Box<dyn Error>> {
+    match task.backend {
+        Backend::Playwright => perform_scraping(task, registry).await,
+        Backend::WebDriver => {
+            let webdriver_url = task
+                .webdriver_url
+                .as_deref()
+                .ok_or("Backend::WebDriver requires ScrapingTask.webdriver_url")?;
+            let driver =
+                driver::WebDriverDriver::connect(webdriver_url, thirtyfour::DesiredCapabilities::chrome())
+                    .await?;
+            perform_scraping_with_driver(&driver, task).await
+        }
+    }
 }
 
 pub fn load_json(filename: &str) -> Result<Vec<ScrapingTask>, Box<dyn Error>> {
@@ -258,4 +791,89 @@ mod tests {
         browser.close().await.unwrap();
         page.close(None).await.unwrap();
     }
+
+    #[test]
+    fn merge_into_overwrites_scalars() {
+        let mut record = serde_json::Map::new();
+        merge_into(&mut record, "title".to_string(), serde_json::json!("first"));
+        merge_into(&mut record, "title".to_string(), serde_json::json!("second"));
+        assert_eq!(record.get("title"), Some(&serde_json::json!("second")));
+    }
+
+    #[test]
+    fn merge_into_appends_arrays() {
+        let mut record = serde_json::Map::new();
+        merge_into(&mut record, "items".to_string(), serde_json::json!(["a", "b"]));
+        merge_into(&mut record, "items".to_string(), serde_json::json!(["c"]));
+        assert_eq!(record.get("items"), Some(&serde_json::json!(["a", "b", "c"])));
+    }
+
+    #[test]
+    fn shape_extract_single_returns_first_match() {
+        let value = shape_extract(vec!["a".to_string(), "b".to_string()], false);
+        assert_eq!(value, Some(serde_json::json!("a")));
+    }
+
+    #[test]
+    fn shape_extract_single_with_no_matches_is_none() {
+        assert_eq!(shape_extract(Vec::new(), false), None);
+    }
+
+    #[test]
+    fn shape_extract_multiple_returns_array_even_when_empty() {
+        let value = shape_extract(Vec::new(), true);
+        assert_eq!(value, Some(serde_json::json!([])));
+    }
+
+    #[test]
+    fn shape_extract_multiple_collects_all_matches() {
+        let value = shape_extract(vec!["a".to_string(), "b".to_string()], true);
+        assert_eq!(value, Some(serde_json::json!(["a", "b"])));
+    }
+
+    #[test]
+    fn action_name_labels_each_variant() {
+        assert_eq!(
+            action_name(&Action::GoTo {
+                url: "https://example.com".to_string()
+            }),
+            "GoTo"
+        );
+        assert_eq!(
+            action_name(&Action::Extract {
+                selector: ".item".to_string(),
+                attribute: None,
+                key: "items".to_string(),
+                multiple: true,
+            }),
+            "Extract"
+        );
+        assert_eq!(
+            action_name(&Action::WithinFrame {
+                frame_selector: "#widget".to_string(),
+                actions: Vec::new(),
+            }),
+            "WithinFrame"
+        );
+    }
+
+    #[test]
+    fn wait_for_state_maps_to_playwright_frame_state() {
+        assert!(matches!(
+            playwright::api::FrameState::from(WaitForState::Attached),
+            playwright::api::FrameState::Attached
+        ));
+        assert!(matches!(
+            playwright::api::FrameState::from(WaitForState::Visible),
+            playwright::api::FrameState::Visible
+        ));
+        assert!(matches!(
+            playwright::api::FrameState::from(WaitForState::Hidden),
+            playwright::api::FrameState::Hidden
+        ));
+        assert!(matches!(
+            playwright::api::FrameState::from(WaitForState::Detached),
+            playwright::api::FrameState::Detached
+        ));
+    }
 }
@@ -0,0 +1,34 @@
+use serde::{Deserialize, Serialize};
+
+/// Outcome of a single action within a report.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum ActionOutcome {
+    Ok,
+    Failed(String),
+}
+
+/// A progress event emitted for each action as `perform_scraping_reported` runs,
+/// so callers get machine-readable progress instead of ad-hoc `println!`s.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum ActionEvent {
+    Start {
+        index: usize,
+        action_name: String,
+    },
+    Finished {
+        index: usize,
+        action_name: String,
+        duration_ms: u128,
+        result: ActionOutcome,
+    },
+}
+
+/// Summary of a completed `ScrapingTask` run, serializable to JSON.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TaskReport {
+    pub name: String,
+    pub total: usize,
+    pub passed: usize,
+    pub failed: usize,
+    pub total_duration_ms: u128,
+}